@@ -1,110 +1,862 @@
-use clap::{Arg, Command}; // Importing clap for command-line argument parsing
+use chrono::{DateTime, Datelike, Duration, Local, TimeZone, Utc, Weekday};
+use clap::{Arg, ArgAction, Command}; // Importing clap for command-line argument parsing
+use prettytable::{row, Cell, Row, Table};
 use serde::{Deserialize, Serialize}; // Importing serde for serializing and deserializing JSON
+use std::collections::HashSet;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
 use std::{fs, process}; // Importing fs for file system operations and process for handling errors
 
+// Resolves the path to the tasks data file: an explicit `--data-file`
+// override, or else the XDG data directory (falling back to the current
+// directory on platforms without one).
+fn resolve_data_file(override_path: Option<&String>) -> PathBuf {
+    if let Some(path) = override_path {
+        return PathBuf::from(path);
+    }
+
+    match xdg::BaseDirectories::with_prefix("task-manager-cli") {
+        Ok(dirs) => dirs
+            .place_data_file("data.json")
+            .unwrap_or_else(|_| PathBuf::from("tasks.json")),
+        Err(_) => PathBuf::from("tasks.json"),
+    }
+}
+
+// The archive store lives alongside the active data file, under "finished.json"
+fn resolve_archive_file(data_file: &Path) -> PathBuf {
+    data_file.with_file_name("finished.json")
+}
+
+// The lifecycle state of a task
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+enum Status {
+    #[default]
+    Pending,
+    InProgress,
+    Paused,
+    Done,
+}
+
+// A three-level task priority
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+enum Priority {
+    High,
+    #[default]
+    Medium,
+    Low,
+}
+
+impl Priority {
+    fn parse(input: &str) -> Option<Priority> {
+        match input.to_lowercase().as_str() {
+            "high" => Some(Priority::High),
+            "medium" => Some(Priority::Medium),
+            "low" => Some(Priority::Low),
+            _ => None,
+        }
+    }
+
+    fn label(&self) -> &'static str {
+        match self {
+            Priority::High => "High",
+            Priority::Medium => "Medium",
+            Priority::Low => "Low",
+        }
+    }
+}
+
 // Struct that represents a Task
+// Deserialized via TaskOnDisk below (#[serde(from = ...)]), so field-level defaults live there,
+// not here; Serialize is still derived normally and always writes every field.
 #[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(from = "TaskOnDisk")]
 struct Task {
+    // Stable identity, independent of position in the backing Vec: display
+    // order (sorted/grouped listings, reordering) must never change which
+    // task a given ID refers to.
+    id: u64,
+    description: String,
+    status: Status,
+    deadline: Option<DateTime<Utc>>,
+    started_at: Option<DateTime<Utc>>,
+    finished_at: Option<DateTime<Utc>>,
+    // Accumulated time spent in-progress, in seconds
+    total_duration: i64,
+    priority: Priority,
+    created_at: DateTime<Utc>,
+    tags: Vec<String>,
+}
+
+impl Task {
+    fn is_completed(&self) -> bool {
+        self.status == Status::Done
+    }
+}
+
+// The on-disk shape Task deserializes through, so a file saved before `status` existed still
+// lands on the right value: a present `status` wins, otherwise the old boolean `completed`
+// field (if any) maps onto Done/Pending. Task's Serialize is untouched, so saves always emit
+// the new schema.
+#[derive(Deserialize)]
+struct TaskOnDisk {
+    #[serde(default)]
+    id: u64,
     description: String,
-    completed: bool,
+    status: Option<Status>,
+    #[serde(default)]
+    completed: Option<bool>,
+    #[serde(default)]
+    deadline: Option<DateTime<Utc>>,
+    #[serde(default)]
+    started_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    finished_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    total_duration: i64,
+    #[serde(default)]
+    priority: Priority,
+    #[serde(default = "Utc::now")]
+    created_at: DateTime<Utc>,
+    #[serde(default)]
+    tags: Vec<String>,
+}
+
+impl From<TaskOnDisk> for Task {
+    fn from(raw: TaskOnDisk) -> Task {
+        let status = raw.status.unwrap_or(match raw.completed {
+            Some(true) => Status::Done,
+            _ => Status::Pending,
+        });
+        Task {
+            id: raw.id,
+            description: raw.description,
+            status,
+            deadline: raw.deadline,
+            started_at: raw.started_at,
+            finished_at: raw.finished_at,
+            total_duration: raw.total_duration,
+            priority: raw.priority,
+            created_at: raw.created_at,
+            tags: raw.tags,
+        }
+    }
+}
+
+// Parses a `--due` value into a concrete UTC timestamp, accepting either an
+// RFC3339 timestamp or a handful of fuzzy phrases ("today", "tomorrow",
+// "next monday", "in 3 days").
+fn parse_due_date(input: &str) -> Option<DateTime<Utc>> {
+    let trimmed = input.trim();
+
+    if let Ok(parsed) = DateTime::parse_from_rfc3339(trimmed) {
+        return Some(parsed.with_timezone(&Utc));
+    }
+
+    let lower = trimmed.to_lowercase();
+    let now = Local::now();
+
+    if lower == "today" {
+        let end_of_day = now.date_naive().and_hms_opt(23, 59, 59).unwrap();
+        return Some(Local.from_local_datetime(&end_of_day).unwrap().with_timezone(&Utc));
+    }
+    if lower == "tomorrow" {
+        return Some((now + Duration::days(1)).with_timezone(&Utc));
+    }
+
+    if let Some(days_str) = lower.strip_prefix("in ").and_then(|s| s.strip_suffix(" days")) {
+        if let Ok(days) = days_str.trim().parse::<i64>() {
+            return Some((now + Duration::days(days)).with_timezone(&Utc));
+        }
+    }
+    if let Some(day_str) = lower.strip_prefix("in ").and_then(|s| s.strip_suffix(" day")) {
+        if let Ok(days) = day_str.trim().parse::<i64>() {
+            return Some((now + Duration::days(days)).with_timezone(&Utc));
+        }
+    }
+
+    if let Some(day_name) = lower.strip_prefix("next ") {
+        let target = match day_name {
+            "monday" => Weekday::Mon,
+            "tuesday" => Weekday::Tue,
+            "wednesday" => Weekday::Wed,
+            "thursday" => Weekday::Thu,
+            "friday" => Weekday::Fri,
+            "saturday" => Weekday::Sat,
+            "sunday" => Weekday::Sun,
+            _ => return None,
+        };
+        let mut candidate = now + Duration::days(1);
+        while candidate.weekday() != target {
+            candidate += Duration::days(1);
+        }
+        return Some(candidate.with_timezone(&Utc));
+    }
+
+    None
 }
 
 // Function to load tasks from a JSON file
-fn load_tasks() -> Vec<Task> {
-    if let Ok(tasks_json) = fs::read_to_string("tasks.json") {
-        if let Ok(tasks) = serde_json::from_str(&tasks_json) {
-            return tasks;
+fn load_tasks(path: &Path) -> Vec<Task> {
+    let mut tasks: Vec<Task> = if let Ok(tasks_json) = fs::read_to_string(path) {
+        serde_json::from_str(&tasks_json).unwrap_or_default()
+    } else {
+        vec![]
+    };
+    backfill_missing_ids(&mut tasks);
+    tasks
+}
+
+// Repairs ids right after loading: a tasks.json written before ids existed has every task
+// default to id 0 (they all collide), and a hand-edited file could duplicate an id outright.
+// Either case gets a fresh, unique id assigned here, before any command can act on an ambiguous
+// task.
+fn backfill_missing_ids(tasks: &mut [Task]) {
+    let mut next_id = tasks.iter().map(|t| t.id).max().unwrap_or(0) + 1;
+    let mut seen = HashSet::new();
+    for task in tasks.iter_mut() {
+        if task.id != 0 && seen.insert(task.id) {
+            continue;
         }
+        task.id = next_id;
+        seen.insert(next_id);
+        next_id += 1;
     }
-    vec![]
 }
 
 // Function to save tasks to a JSON file
-fn save_tasks(tasks: &Vec<Task>) {
+fn save_tasks(tasks: &Vec<Task>, path: &Path) {
+    if let Some(parent) = path.parent() {
+        let _ = fs::create_dir_all(parent);
+    }
     let tasks_json = serde_json::to_string_pretty(tasks).expect("Error serializing tasks.");
-    fs::write("tasks.json", tasks_json).expect("Error saving tasks.");
+    fs::write(path, tasks_json).expect("Error saving tasks.");
+}
+
+// The next stable task ID to hand out: one past the highest ID in use across
+// both the active list and the archive, so IDs stay unique even after a task
+// has been archived and the active list has been fully re-populated.
+fn next_task_id(tasks: &[Task], archived: &[Task]) -> u64 {
+    tasks.iter().chain(archived.iter()).map(|t| t.id).max().unwrap_or(0) + 1
 }
 
-// Function to list all tasks, optionally filtered by a status (e.g., pending or completed)
-fn list_tasks(tasks: &Vec<Task>, filter: Option<String>) {
+// Function to list all tasks, optionally filtered by status and/or tag, grouped by priority,
+// and rendered as a colored table (or plain, unstyled rows with `plain`, for scripting)
+fn list_tasks(tasks: &[Task], filter: Option<String>, tag: Option<String>, group_by_priority: bool, plain: bool) {
     let filter = filter.unwrap_or_else(|| String::from(""));
+    let now = Utc::now();
 
-    // Filter tasks based on the filter value ('pending', 'completed', or none)
-    let filtered_tasks: Vec<&Task> = tasks.iter()
+    // Filter tasks based on the filter value ('pending', 'inprogress', 'paused', 'completed', 'overdue', or none)
+    // and, if given, by tag membership
+    let mut filtered_tasks: Vec<&Task> = tasks.iter()
         .filter(|t| {
             match filter.as_str() {
-                "pending" => !t.completed,
-                "completed" => t.completed,
+                "pending" => t.status == Status::Pending,
+                "inprogress" => t.status == Status::InProgress,
+                "paused" => t.status == Status::Paused,
+                "completed" => t.is_completed(),
+                "overdue" => !t.is_completed() && t.deadline.is_some_and(|d| d < now),
                 _ => true,
             }
         })
+        .filter(|t| tag.as_ref().is_none_or(|tag| t.tags.iter().any(|t| t == tag)))
         .collect();
 
-    // Print the filtered tasks, or a message if no tasks were found
     if filtered_tasks.is_empty() {
         println!("No tasks found.");
-    } else {
-        // Loop through each filtered task and print its description and status
-        for (index, task) in filtered_tasks.iter().enumerate() {
-            let status = if task.completed { "Completed" } else { "Pending" };
-            println!("{}. {} ({})", index + 1, task.description, status);
+        return;
+    }
+
+    if group_by_priority {
+        // One "High:"/"Medium:"/"Low:" section per non-empty bucket, each its
+        // own table/plain listing (manual/deadline order preserved within a bucket).
+        for priority in [Priority::High, Priority::Medium, Priority::Low] {
+            let group: Vec<&Task> = filtered_tasks.iter().copied().filter(|t| t.priority == priority).collect();
+            if group.is_empty() {
+                continue;
+            }
+            println!("{}:", priority.label());
+            if plain {
+                print_plain(&group, now);
+            } else {
+                print_table(&group, now);
+            }
         }
+        return;
+    }
+
+    // Sort pending tasks by deadline (soonest first), pushing tasks without a deadline to the end.
+    // This is a stable sort, so tasks sharing a deadline (including no deadline at all) keep their
+    // manual vector order, which is what the `priority` reordering subcommand manipulates.
+    filtered_tasks.sort_by_key(|t| t.deadline.unwrap_or(DateTime::<Utc>::MAX_UTC));
+
+    if plain {
+        print_plain(&filtered_tasks, now);
+    } else {
+        print_table(&filtered_tasks, now);
     }
 }
 
+// Prints tasks as a colored table: ID, status (green/yellow/red), description, tags, deadline
+fn print_table(tasks: &[&Task], now: DateTime<Utc>) {
+    let mut table = Table::new();
+    table.add_row(row!["ID", "Status", "Description", "Priority", "Tags", "Deadline"]);
+
+    for task in tasks.iter() {
+        let overdue = !task.is_completed() && task.deadline.is_some_and(|d| d < now);
+        let status_label = status_label(task.status);
+        // Style via prettytable's own Cell API rather than wrapping the label
+        // with `colored`: colored's ANSI escapes count toward prettytable's
+        // column-width calculation, throwing off alignment against the
+        // uncolored header.
+        let style = if overdue {
+            "Fr"
+        } else if task.is_completed() {
+            "Fg"
+        } else {
+            "Fy"
+        };
+        let status_cell = Cell::new(status_label).style_spec(style);
+        let deadline = match task.deadline {
+            Some(d) if overdue => format!("{} [OVERDUE]", d.to_rfc3339()),
+            Some(d) => d.to_rfc3339(),
+            None => String::new(),
+        };
+        table.add_row(Row::new(vec![
+            Cell::new(&task.id.to_string()),
+            status_cell,
+            Cell::new(&task.description),
+            Cell::new(task.priority.label()),
+            Cell::new(&task.tags.join(", ")),
+            Cell::new(&deadline),
+        ]));
+    }
+
+    table.printstd();
+}
+
+// Prints tasks as plain, unstyled rows for scripting
+fn print_plain(tasks: &[&Task], now: DateTime<Utc>) {
+    for task in tasks.iter() {
+        println!("{}", format_task_line(task.id, task, now));
+    }
+}
+
+fn status_label(status: Status) -> &'static str {
+    match status {
+        Status::Pending => "Pending",
+        Status::InProgress => "In Progress",
+        Status::Paused => "Paused",
+        Status::Done => "Done",
+    }
+}
+
+// Formats a single task line for display: stable ID, description, status, tracked time, and deadline
+fn format_task_line(id: u64, task: &Task, now: DateTime<Utc>) -> String {
+    let tracked = format_duration(task.total_duration);
+    let deadline_suffix = match task.deadline {
+        Some(deadline) => {
+            let overdue = !task.is_completed() && deadline < now;
+            let marker = if overdue { " [OVERDUE]" } else { "" };
+            format!(" - due {}{}", deadline.to_rfc3339(), marker)
+        }
+        None => String::new(),
+    };
+    let tags_suffix = if task.tags.is_empty() {
+        String::new()
+    } else {
+        format!(" #{}", task.tags.join(" #"))
+    };
+    format!(
+        "{}. {} ({}, {}){}{}",
+        id,
+        task.description,
+        status_label(task.status),
+        tracked,
+        deadline_suffix,
+        tags_suffix
+    )
+}
+
+// Formats a duration given in seconds as "HhMMm" for display
+fn format_duration(total_seconds: i64) -> String {
+    let hours = total_seconds / 3600;
+    let minutes = (total_seconds % 3600) / 60;
+    format!("{}h{:02}m tracked", hours, minutes)
+}
+
 // Function to add a new task
-fn add_task(tasks: &mut Vec<Task>, description: String) {
-    let new_task = Task { description: description.clone(), completed: false };
+fn add_task(
+    tasks: &mut Vec<Task>,
+    description: String,
+    due: Option<String>,
+    priority: Option<String>,
+    tags: Vec<String>,
+    path: &Path,
+    archive_path: &Path,
+) {
+    let deadline = due.as_deref().and_then(parse_due_date);
+    if due.is_some() && deadline.is_none() {
+        eprintln!("Could not understand due date, ignoring it.");
+    }
+    let priority = match priority.as_deref().map(Priority::parse) {
+        Some(Some(p)) => p,
+        Some(None) => {
+            eprintln!("Unknown priority, defaulting to medium.");
+            Priority::Medium
+        }
+        None => Priority::Medium,
+    };
+    let archived = load_tasks(archive_path);
+    let id = next_task_id(tasks, &archived);
+    let new_task = Task {
+        id,
+        description: description.clone(),
+        status: Status::Pending,
+        deadline,
+        started_at: None,
+        finished_at: None,
+        total_duration: 0,
+        priority,
+        created_at: Utc::now(),
+        tags,
+    };
     tasks.push(new_task);
-    save_tasks(tasks);
-    println!("Task '{}' added successfully!", description);
+    save_tasks(tasks, path);
+    println!("Task '{}' added with id {}!", description, id);
 }
 
-// Function to mark a task as completed based on its ID
-fn mark_task_as_completed(tasks: &mut Vec<Task>, id: usize) {
-    if id > 0 && id <= tasks.len() {
-        tasks[id - 1].completed = true;
-        save_tasks(tasks);
-        println!("Task {} marked as completed!", id);
-    } else {
+// Moves a task to be immediately before or after another task, reindexing the rest of the list
+fn reorder_task(tasks: &mut Vec<Task>, id: u64, other_id: u64, before: bool, path: &Path) {
+    if id == other_id {
         println!("Invalid task ID.");
+        return;
+    }
+
+    let from = match tasks.iter().position(|t| t.id == id) {
+        Some(idx) => idx,
+        None => {
+            println!("Invalid task ID.");
+            return;
+        }
+    };
+    let task = tasks.remove(from);
+
+    // Position lookup happens against the already-shortened Vec, so it's
+    // automatically adjusted for the removal above.
+    let other_index = match tasks.iter().position(|t| t.id == other_id) {
+        Some(idx) => idx,
+        None => {
+            tasks.insert(from, task);
+            println!("Invalid task ID.");
+            return;
+        }
+    };
+    let insert_at = if before { other_index } else { other_index + 1 };
+
+    tasks.insert(insert_at, task);
+    save_tasks(tasks, path);
+    println!("Task {} moved {} task {}!", id, if before { "before" } else { "after" }, other_id);
+}
+
+// Marks a task as completed, moving it out of the active list and into the archive store
+fn mark_task_as_completed(tasks: &mut Vec<Task>, id: u64, path: &Path, archive_path: &Path) {
+    match tasks.iter().position(|t| t.id == id) {
+        Some(idx) => {
+            let mut task = tasks.remove(idx);
+            close_out_task(&mut task);
+            save_tasks(tasks, path);
+
+            let mut archived = load_tasks(archive_path);
+            archived.push(task);
+            save_tasks(&archived, archive_path);
+
+            println!("Task {} marked as completed and archived!", id);
+        }
+        None => println!("Invalid task ID."),
+    }
+}
+
+// Stops time tracking and marks a task Done, folding any remaining elapsed time into total_duration
+fn close_out_task(task: &mut Task) {
+    if let Some(started_at) = task.started_at.take() {
+        task.total_duration += (Utc::now() - started_at).num_seconds();
+    }
+    task.status = Status::Done;
+    task.finished_at = Some(Utc::now());
+}
+
+// Lists the tasks sitting in the archive store
+fn list_archive(archive_path: &Path) {
+    let archived = load_tasks(archive_path);
+    list_tasks(&archived, None, None, false, false);
+}
+
+// Restores a task from the archive store back into the active list as Pending
+fn restore_task(id: u64, path: &Path, archive_path: &Path) {
+    let mut archived = load_tasks(archive_path);
+    match archived.iter().position(|t| t.id == id) {
+        Some(idx) => {
+            let mut task = archived.remove(idx);
+            task.status = Status::Pending;
+            task.finished_at = None;
+            save_tasks(&archived, archive_path);
+
+            let mut tasks = load_tasks(path);
+            tasks.push(task);
+            save_tasks(&tasks, path);
+
+            println!("Task {} restored to the active list!", id);
+        }
+        None => println!("Invalid task ID."),
+    }
+}
+
+// Starts (or resumes) time tracking on a task, setting it to InProgress
+fn start_task(tasks: &mut Vec<Task>, id: u64, path: &Path) {
+    match tasks.iter_mut().find(|t| t.id == id) {
+        Some(task) => {
+            task.status = Status::InProgress;
+            task.started_at = Some(Utc::now());
+            save_tasks(tasks, path);
+            println!("Task {} started!", id);
+        }
+        None => println!("Invalid task ID."),
+    }
+}
+
+// Pauses an in-progress task, folding the elapsed time into total_duration
+fn pause_task(tasks: &mut Vec<Task>, id: u64, path: &Path) {
+    match tasks.iter_mut().find(|t| t.id == id) {
+        Some(task) => {
+            if let Some(started_at) = task.started_at.take() {
+                task.total_duration += (Utc::now() - started_at).num_seconds();
+            }
+            task.status = Status::Paused;
+            save_tasks(tasks, path);
+            println!("Task {} paused!", id);
+        }
+        None => println!("Invalid task ID."),
     }
 }
 
 // Function to remove a task based on its ID
-fn remove_task(tasks: &mut Vec<Task>, id: usize) {
-    if id > 0 && id <= tasks.len() {
-        let removed_task = tasks.remove(id - 1);
-        save_tasks(tasks);
-        println!("Task '{}' removed successfully!", removed_task.description);
+fn remove_task(tasks: &mut Vec<Task>, id: u64, path: &Path) {
+    match tasks.iter().position(|t| t.id == id) {
+        Some(idx) => {
+            let removed_task = tasks.remove(idx);
+            save_tasks(tasks, path);
+            println!("Task '{}' removed successfully!", removed_task.description);
+        }
+        None => println!("Invalid task ID."),
+    }
+}
+
+// Edits a task's description and metadata, either directly via `--description`/`--priority`/
+// `--due`/`--tag` or, when none of those are given, interactively via $EDITOR
+fn edit_task(
+    tasks: &mut Vec<Task>,
+    id: u64,
+    description: Option<String>,
+    priority: Option<String>,
+    due: Option<String>,
+    tags: Option<Vec<String>>,
+    path: &Path,
+) {
+    let idx = match tasks.iter().position(|t| t.id == id) {
+        Some(idx) => idx,
+        None => {
+            println!("Invalid task ID.");
+            return;
+        }
+    };
+
+    if description.is_some() || priority.is_some() || due.is_some() || tags.is_some() {
+        if let Some(description) = description {
+            tasks[idx].description = description;
+        }
+        if let Some(priority) = priority {
+            match Priority::parse(&priority) {
+                Some(p) => tasks[idx].priority = p,
+                None => eprintln!("Unknown priority, leaving it unchanged."),
+            }
+        }
+        if let Some(due) = due {
+            match parse_due_date(&due) {
+                Some(deadline) => tasks[idx].deadline = Some(deadline),
+                None => eprintln!("Could not understand due date, leaving it unchanged."),
+            }
+        }
+        if let Some(tags) = tags {
+            tasks[idx].tags = tags;
+        }
     } else {
-        println!("Invalid task ID.");
+        match edit_task_in_editor(&tasks[idx]) {
+            Some(edited) => {
+                tasks[idx].description = edited.description;
+                tasks[idx].priority = edited.priority;
+                tasks[idx].tags = edited.tags;
+                tasks[idx].deadline = edited.deadline;
+            }
+            None => {
+                eprintln!("Edit aborted.");
+                return;
+            }
+        }
+    }
+
+    save_tasks(tasks, path);
+    println!("Task {} updated!", id);
+}
+
+// The fields edit_task_in_editor round-trips through the temp file
+struct EditedTask {
+    description: String,
+    priority: Priority,
+    tags: Vec<String>,
+    deadline: Option<DateTime<Utc>>,
+}
+
+// Opens the task's description and metadata in $EDITOR (falling back to `vi`) via a temp file,
+// returning the parsed result, or None if the edit was aborted (non-zero exit, or description
+// left empty)
+fn edit_task_in_editor(current: &Task) -> Option<EditedTask> {
+    let editor = std::env::var("EDITOR").unwrap_or_else(|_| String::from("vi"));
+    let temp_path = std::env::temp_dir().join(format!("task-manager-cli-edit-{}.txt", process::id()));
+
+    let template = format!(
+        "{}\n\n# Everything above this line is the description.\n# Edit the fields below to change priority/tags/due date; leave a field\n# blank to clear it (priority cannot be cleared, only changed).\npriority: {}\ntags: {}\ndue: {}\n",
+        current.description,
+        current.priority.label().to_lowercase(),
+        current.tags.join(", "),
+        current.deadline.map(|d| d.to_rfc3339()).unwrap_or_default(),
+    );
+    fs::write(&temp_path, &template).ok()?;
+
+    let status = process::Command::new(&editor).arg(&temp_path).status().ok()?;
+    if !status.success() {
+        let _ = fs::remove_file(&temp_path);
+        return None;
+    }
+
+    let edited = fs::read_to_string(&temp_path).ok()?;
+    let _ = fs::remove_file(&temp_path);
+
+    parse_edited_task(&edited, current)
+}
+
+// Parses the text produced by editing edit_task_in_editor's template back into an EditedTask,
+// falling back to `current`'s values for any field whose line is missing or unrecognized
+fn parse_edited_task(text: &str, current: &Task) -> Option<EditedTask> {
+    let mut description_lines = Vec::new();
+    let mut priority = current.priority;
+    let mut tags = current.tags.clone();
+    let mut deadline = current.deadline;
+    let mut past_description = false;
+
+    for line in text.lines() {
+        let trimmed = line.trim();
+        if !past_description {
+            if trimmed.starts_with('#') {
+                past_description = true;
+            } else {
+                description_lines.push(line);
+            }
+            continue;
+        }
+
+        if let Some(value) = trimmed.strip_prefix("priority:") {
+            let value = value.trim();
+            if !value.is_empty() {
+                match Priority::parse(value) {
+                    Some(p) => priority = p,
+                    None => eprintln!("Unknown priority '{}', leaving it unchanged.", value),
+                }
+            }
+        } else if let Some(value) = trimmed.strip_prefix("tags:") {
+            tags = value
+                .split(',')
+                .map(|t| t.trim().to_string())
+                .filter(|t| !t.is_empty())
+                .collect();
+        } else if let Some(value) = trimmed.strip_prefix("due:") {
+            let value = value.trim();
+            if value.is_empty() {
+                deadline = None;
+            } else if let Some(d) = parse_due_date(value) {
+                deadline = Some(d);
+            } else {
+                eprintln!("Could not understand due date '{}', leaving it unchanged.", value);
+            }
+        }
+    }
+
+    let description = description_lines.join("\n").trim().to_string();
+    if description.is_empty() {
+        None
+    } else {
+        Some(EditedTask { description, priority, tags, deadline })
+    }
+}
+
+// A task as Taskwarrior's JSON export/import format represents it
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct TaskwarriorTask {
+    description: String,
+    status: String,
+    entry: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    end: Option<String>,
+    #[serde(default)]
+    tags: Vec<String>,
+}
+
+// Formats a timestamp the way Taskwarrior does, e.g. "20230101T120000Z"
+fn format_tw_date(dt: DateTime<Utc>) -> String {
+    dt.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+// Parses a Taskwarrior-formatted timestamp
+fn parse_tw_date(s: &str) -> Option<DateTime<Utc>> {
+    chrono::NaiveDateTime::parse_from_str(s, "%Y%m%dT%H%M%SZ")
+        .ok()
+        .map(|naive| naive.and_utc())
+}
+
+// Maps our status onto Taskwarrior's vocabulary (pending/completed)
+fn status_to_tw(status: Status) -> &'static str {
+    match status {
+        Status::Done => "completed",
+        Status::Pending | Status::InProgress | Status::Paused => "pending",
+    }
+}
+
+// Maps a Taskwarrior status onto ours
+fn status_from_tw(status: &str) -> Status {
+    match status {
+        "completed" | "deleted" => Status::Done,
+        _ => Status::Pending,
+    }
+}
+
+// Writes the active tasks to stdout as a Taskwarrior-compatible JSON array
+fn export_tasks(tasks: &[Task]) {
+    let tw_tasks: Vec<TaskwarriorTask> = tasks
+        .iter()
+        .map(|t| TaskwarriorTask {
+            description: t.description.clone(),
+            status: status_to_tw(t.status).to_string(),
+            entry: format_tw_date(t.created_at),
+            end: t.finished_at.map(format_tw_date),
+            tags: t.tags.clone(),
+        })
+        .collect();
+
+    let json = serde_json::to_string_pretty(&tw_tasks).expect("Error serializing tasks.");
+    println!("{}", json);
+}
+
+// Reads a Taskwarrior-compatible JSON array from stdin and merges it into the active tasks
+fn import_tasks(tasks: &mut Vec<Task>, path: &Path, archive_path: &Path) {
+    let mut input = String::new();
+    io::stdin()
+        .read_to_string(&mut input)
+        .expect("Error reading from stdin.");
+
+    let tw_tasks: Vec<TaskwarriorTask> = serde_json::from_str(&input).unwrap_or_else(|err| {
+        eprintln!("Invalid Taskwarrior JSON: {}", err);
+        process::exit(1);
+    });
+
+    let archived = load_tasks(archive_path);
+    let first_id = next_task_id(tasks, &archived);
+    let imported = tw_tasks.len();
+    for (next_id, tw_task) in (first_id..).zip(tw_tasks) {
+        tasks.push(Task {
+            id: next_id,
+            description: tw_task.description,
+            status: status_from_tw(&tw_task.status),
+            deadline: None,
+            started_at: None,
+            finished_at: tw_task.end.as_deref().and_then(parse_tw_date),
+            total_duration: 0,
+            priority: Priority::Medium,
+            created_at: parse_tw_date(&tw_task.entry).unwrap_or_else(Utc::now),
+            tags: tw_task.tags,
+        });
     }
+
+    save_tasks(tasks, path);
+    println!("Imported {} task(s).", imported);
 }
 
 // Main function that processes command-line arguments and manages tasks
 fn main() {
-    println!("{{\n");
-
     // Parsing command-line arguments using clap
     let matches = Command::new("Task Manager CLI")
         .version("1.0")
         .about("CLI to manage pending tasks")
         .after_help("}")
+        .arg(Arg::new("data-file")
+            .help("Overrides the path to the tasks data file")
+            .long("data-file")
+            .global(true)
+            .num_args(1))
         .subcommand(Command::new("add")
             .about("Adds a new task")
             .after_help("}")
             .arg(Arg::new("description")
                 .help("Task description")
                 .required(true)
-                .index(1)))
+                .index(1))
+            .arg(Arg::new("due")
+                .help("Due date, e.g. an RFC3339 timestamp, 'tomorrow', 'next monday', or 'in 3 days'")
+                .long("due")
+                .num_args(1))
+            .arg(Arg::new("priority")
+                .help("Priority: 'high', 'medium', or 'low' (default: medium)")
+                .long("priority")
+                .num_args(1))
+            .arg(Arg::new("tag")
+                .help("Attaches a tag; may be given multiple times")
+                .long("tag")
+                .action(ArgAction::Append)
+                .num_args(1)))
         .subcommand(Command::new("list")
             .about("Lists the tasks")
             .after_help("}")
             .arg(Arg::new("filter")
-                .help("Filters by 'pending' or 'completed'")
+                .help("Filters by 'pending', 'inprogress', 'paused', 'completed', or 'overdue'")
                 .long("filter")
-                .num_args(1)))
+                .num_args(1))
+            .arg(Arg::new("tag")
+                .help("Filters to tasks carrying this tag")
+                .long("tag")
+                .num_args(1))
+            .arg(Arg::new("group-by-priority")
+                .help("Groups the listing by priority (high/medium/low)")
+                .long("group-by-priority")
+                .action(ArgAction::SetTrue))
+            .arg(Arg::new("plain")
+                .help("Machine-readable, unstyled output (no table, no color)")
+                .long("plain")
+                .action(ArgAction::SetTrue)))
+        .subcommand(Command::new("priority")
+            .about("Reorders a task relative to another")
+            .after_help("}")
+            .arg(Arg::new("id")
+                .help("Task ID to move")
+                .required(true)
+                .index(1))
+            .arg(Arg::new("relation")
+                .help("'before' or 'after'")
+                .required(true)
+                .value_parser(["before", "after"])
+                .index(2))
+            .arg(Arg::new("other_id")
+                .help("Task ID to move relative to")
+                .required(true)
+                .index(3)))
         .subcommand(Command::new("complete")
             .about("Marks a task as completed")
             .after_help("}")
@@ -112,6 +864,27 @@ fn main() {
                 .help("Task ID")
                 .required(true)
                 .index(1)))
+        .subcommand(Command::new("start")
+            .about("Starts (or resumes) time tracking on a task")
+            .after_help("}")
+            .arg(Arg::new("id")
+                .help("Task ID")
+                .required(true)
+                .index(1)))
+        .subcommand(Command::new("pause")
+            .about("Pauses time tracking on a task")
+            .after_help("}")
+            .arg(Arg::new("id")
+                .help("Task ID")
+                .required(true)
+                .index(1)))
+        .subcommand(Command::new("finish")
+            .about("Finishes a task, stops time tracking, and archives it")
+            .after_help("}")
+            .arg(Arg::new("id")
+                .help("Task ID")
+                .required(true)
+                .index(1)))
         .subcommand(Command::new("remove")
             .about("Removes a task")
             .after_help("}")
@@ -119,46 +892,197 @@ fn main() {
                 .help("Task ID")
                 .required(true)
                 .index(1)))
+        .subcommand(Command::new("edit")
+            .about("Edits a task's description and metadata, interactively via $EDITOR or with flags")
+            .after_help("}")
+            .arg(Arg::new("id")
+                .help("Task ID")
+                .required(true)
+                .index(1))
+            .arg(Arg::new("description")
+                .help("New description (skips opening $EDITOR)")
+                .long("description")
+                .num_args(1))
+            .arg(Arg::new("priority")
+                .help("New priority: 'high', 'medium', or 'low' (skips opening $EDITOR)")
+                .long("priority")
+                .num_args(1))
+            .arg(Arg::new("due")
+                .help("New due date, same formats as 'add --due' (skips opening $EDITOR)")
+                .long("due")
+                .num_args(1))
+            .arg(Arg::new("tag")
+                .help("Replaces the task's tags; may be given multiple times (skips opening $EDITOR)")
+                .long("tag")
+                .action(ArgAction::Append)
+                .num_args(1)))
+        .subcommand(Command::new("export")
+            .about("Exports tasks to stdout in Taskwarrior's JSON format")
+            .after_help("}"))
+        .subcommand(Command::new("import")
+            .about("Imports tasks from stdin in Taskwarrior's JSON format")
+            .after_help("}"))
+        .subcommand(Command::new("archive")
+            .about("Views or restores finished tasks")
+            .after_help("}")
+            .subcommand(Command::new("list")
+                .about("Lists archived (finished) tasks")
+                .after_help("}"))
+            .subcommand(Command::new("restore")
+                .about("Restores an archived task back into the active list")
+                .after_help("}")
+                .arg(Arg::new("id")
+                    .help("Archived task ID")
+                    .required(true)
+                    .index(1))))
         .get_matches();
 
-    let mut tasks = load_tasks();
+    // 'export' and 'list --plain' exist to produce clean, parseable output for piping (the
+    // Taskwarrior bridge, and scripting), so they skip the `{ ... }` envelope the other
+    // subcommands print around their output.
+    let raw_output = match matches.subcommand() {
+        Some(("export", _)) => true,
+        Some(("list", sub_m)) => sub_m.get_flag("plain"),
+        _ => false,
+    };
+    if !raw_output {
+        println!("{{\n");
+    }
+
+    let data_file = resolve_data_file(matches.get_one::<String>("data-file"));
+    let archive_file = resolve_archive_file(&data_file);
+    let mut tasks = load_tasks(&data_file);
 
     // Match the subcommand and execute the corresponding functionality
     match matches.subcommand() {
         Some(("add", sub_m)) => {
             let description = sub_m.get_one::<String>("description").unwrap().to_string();
-            add_task(&mut tasks, description);
+            let due = sub_m.get_one::<String>("due").map(|d| d.to_string());
+            let priority = sub_m.get_one::<String>("priority").map(|p| p.to_string());
+            let tags = sub_m
+                .get_many::<String>("tag")
+                .map(|values| values.map(|v| v.to_string()).collect())
+                .unwrap_or_default();
+            add_task(&mut tasks, description, due, priority, tags, &data_file, &archive_file);
         }
         Some(("list", sub_m)) => {
             let filter = sub_m.get_one::<String>("filter").map(|f| f.to_string());
-            list_tasks(&tasks, filter);
+            let tag = sub_m.get_one::<String>("tag").map(|t| t.to_string());
+            let group_by_priority = sub_m.get_flag("group-by-priority");
+            let plain = sub_m.get_flag("plain");
+            list_tasks(&tasks, filter, tag, group_by_priority, plain);
+        }
+        Some(("priority", sub_m)) => {
+            let id: u64 = sub_m.get_one::<String>("id")
+                .unwrap()
+                .parse()
+                .unwrap_or_else(|_| {
+                    eprintln!("Invalid ID.");
+                    process::exit(1);
+                });
+            let relation = sub_m.get_one::<String>("relation").unwrap();
+            let other_id: u64 = sub_m.get_one::<String>("other_id")
+                .unwrap()
+                .parse()
+                .unwrap_or_else(|_| {
+                    eprintln!("Invalid ID.");
+                    process::exit(1);
+                });
+            reorder_task(&mut tasks, id, other_id, relation == "before", &data_file);
         }
         Some(("complete", sub_m)) => {
-            let id: usize = sub_m.get_one::<String>("id")
+            let id: u64 = sub_m.get_one::<String>("id")
                 .unwrap()
                 .parse()
                 .unwrap_or_else(|_| {
                     eprintln!("Invalid ID.");
                     process::exit(1);
                 });
-            mark_task_as_completed(&mut tasks, id);
+            mark_task_as_completed(&mut tasks, id, &data_file, &archive_file);
+        }
+        Some(("start", sub_m)) => {
+            let id: u64 = sub_m.get_one::<String>("id")
+                .unwrap()
+                .parse()
+                .unwrap_or_else(|_| {
+                    eprintln!("Invalid ID.");
+                    process::exit(1);
+                });
+            start_task(&mut tasks, id, &data_file);
+        }
+        Some(("pause", sub_m)) => {
+            let id: u64 = sub_m.get_one::<String>("id")
+                .unwrap()
+                .parse()
+                .unwrap_or_else(|_| {
+                    eprintln!("Invalid ID.");
+                    process::exit(1);
+                });
+            pause_task(&mut tasks, id, &data_file);
+        }
+        Some(("finish", sub_m)) => {
+            let id: u64 = sub_m.get_one::<String>("id")
+                .unwrap()
+                .parse()
+                .unwrap_or_else(|_| {
+                    eprintln!("Invalid ID.");
+                    process::exit(1);
+                });
+            mark_task_as_completed(&mut tasks, id, &data_file, &archive_file);
         }
         Some(("remove", sub_m)) => {
-            let id: usize = sub_m.get_one::<String>("id")
+            let id: u64 = sub_m.get_one::<String>("id")
                 .unwrap()
                 .parse()
                 .unwrap_or_else(|_| {
                     eprintln!("Invalid ID.");
                     process::exit(1);
                 });
-            remove_task(&mut tasks, id);
+            remove_task(&mut tasks, id, &data_file);
         }
+        Some(("edit", sub_m)) => {
+            let id: u64 = sub_m.get_one::<String>("id")
+                .unwrap()
+                .parse()
+                .unwrap_or_else(|_| {
+                    eprintln!("Invalid ID.");
+                    process::exit(1);
+                });
+            let description = sub_m.get_one::<String>("description").map(|d| d.to_string());
+            let priority = sub_m.get_one::<String>("priority").map(|p| p.to_string());
+            let due = sub_m.get_one::<String>("due").map(|d| d.to_string());
+            let tags = sub_m
+                .get_many::<String>("tag")
+                .map(|values| values.map(|v| v.to_string()).collect());
+            edit_task(&mut tasks, id, description, priority, due, tags, &data_file);
+        }
+        Some(("export", _)) => export_tasks(&tasks),
+        Some(("import", _)) => import_tasks(&mut tasks, &data_file, &archive_file),
+        Some(("archive", sub_m)) => match sub_m.subcommand() {
+            Some(("list", _)) => list_archive(&archive_file),
+            Some(("restore", restore_m)) => {
+                let id: u64 = restore_m.get_one::<String>("id")
+                    .unwrap()
+                    .parse()
+                    .unwrap_or_else(|_| {
+                        eprintln!("Invalid ID.");
+                        process::exit(1);
+                    });
+                restore_task(id, &data_file, &archive_file);
+            }
+            _ => {
+                eprintln!("Invalid command.");
+                process::exit(1);
+            }
+        },
         _ => {
             eprintln!("Invalid command.");
             process::exit(1);
         }
     }
 
-    println!("\n}}");
+    if !raw_output {
+        println!("\n}}");
+    }
 }
 